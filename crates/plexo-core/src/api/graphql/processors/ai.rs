@@ -78,24 +78,42 @@ impl AIProcessorGraphQLMutation {
     }
 
     async fn chat(&self, ctx: &Context<'_>, input: ChatResponseInput) -> Result<ChatResponseChunk> {
-        let (core, _member_id) = extract_context(ctx).unwrap();
+        let (core, _member_id) = extract_context(ctx)?;
+
+        let mut chat_stream = core
+            .engine
+            .get_chat_response(input)
+            .await
+            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
-        let mut chat_stream = core.engine.get_chat_response(input).await.unwrap();
         let mut last_chunk = None;
 
         while let Some(chunk) = chat_stream.next().await {
-            last_chunk = Some(chunk);
+            // Keep the last successfully streamed chunk, but stop as soon as the
+            // stream reports a failure instead of letting it masquerade as an
+            // empty response.
+            last_chunk = Some(chunk.map_err(|err| async_graphql::Error::new(err.to_string()))?);
         }
 
-        last_chunk.ok_or(SDKError::LLMStreamError.into())
+        last_chunk.ok_or_else(|| SDKError::LLMStreamError.into())
     }
 }
 
 #[Subscription]
 impl AIProcessorGraphQLSubscription {
-    async fn chat(&self, ctx: &Context<'_>, input: ChatResponseInput) -> impl Stream<Item = ChatResponseChunk> {
-        let (core, _member_id) = extract_context(ctx).unwrap();
+    async fn chat(
+        &self,
+        ctx: &Context<'_>,
+        input: ChatResponseInput,
+    ) -> Result<impl Stream<Item = Result<ChatResponseChunk>>> {
+        let (core, _member_id) = extract_context(ctx)?;
+
+        let chat_stream = core
+            .engine
+            .get_chat_response(input)
+            .await
+            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
-        core.engine.get_chat_response(input).await.unwrap()
+        Ok(chat_stream.map(|chunk| chunk.map_err(|err| async_graphql::Error::new(err.to_string()))))
     }
 }
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use async_stream::stream;
+use plexo_sdk::{
+    cognition::suggestions::CognitionCapabilities,
+    errors::sdk::SDKError,
+    resources::messages::message::{Message, MessageBuilder},
+};
+use poem::web::Data;
+use poem_openapi::{
+    payload::{EventStream, Json},
+    ApiResponse, Object, OpenApi,
+};
+use serde_json::json;
+use tokio_stream::StreamExt;
+
+use crate::core::app::Core;
+
+#[derive(Debug, Object)]
+pub struct ChatCompletionMessage {
+    pub role: String,
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Object)]
+pub struct ChatCompletionRequestBody {
+    pub model: String,
+    pub messages: Vec<ChatCompletionMessage>,
+    #[oai(default)]
+    pub stream: bool,
+    pub max_tokens: Option<u16>,
+    pub temperature: Option<f32>,
+}
+
+#[derive(Debug, Object)]
+pub struct ChatCompletionChoice {
+    pub index: u32,
+    pub message: ChatCompletionMessage,
+    pub finish_reason: String,
+}
+
+#[derive(Debug, Object)]
+pub struct ChatCompletionResponse {
+    pub id: String,
+    pub object: String,
+    pub model: String,
+    pub choices: Vec<ChatCompletionChoice>,
+}
+
+#[derive(Debug, Object)]
+pub struct ChatCompletionErrorResponse {
+    pub error: String,
+}
+
+#[derive(ApiResponse)]
+pub enum ChatCompletionApiResponse {
+    #[oai(status = 200)]
+    Full(Json<ChatCompletionResponse>),
+    #[oai(status = 200)]
+    Stream(EventStream<std::pin::Pin<Box<dyn tokio_stream::Stream<Item = String> + Send>>>),
+    #[oai(status = 500)]
+    Error(Json<ChatCompletionErrorResponse>),
+}
+
+#[derive(Default)]
+pub struct OpenAIChatProcessor;
+
+// Used when the request carries no `messages[]` entry with role "system".
+// Such entries are not forwarded as conversation messages: the content of the
+// last one replaces this default as the system message.
+const DEFAULT_SYSTEM_MESSAGE: &str = "You are Plexo, a helpful project management assistant.";
+
+#[OpenApi]
+impl OpenAIChatProcessor {
+    /// OpenAI-compatible `chat/completions`, backed by `SDKEngine::chat_response`
+    /// so existing OpenAI clients can reuse Plexo's task-aware tooling.
+    #[oai(path = "/v1/chat/completions", method = "post")]
+    async fn chat_completions(
+        &self,
+        core: Data<&Arc<Core>>,
+        body: Json<ChatCompletionRequestBody>,
+    ) -> ChatCompletionApiResponse {
+        let body = body.0;
+
+        let mut system_message = DEFAULT_SYSTEM_MESSAGE.to_string();
+        let mut messages = Vec::with_capacity(body.messages.len());
+
+        for message in body.messages {
+            if message.role == "system" {
+                system_message = message.content.unwrap_or_default();
+                continue;
+            }
+
+            messages.push(
+                MessageBuilder::default()
+                    .content(
+                        json!({
+                            "role": message.role,
+                            "content": message.content,
+                        })
+                        .to_string(),
+                    )
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let engine = &core.0.engine;
+        // No authenticated member on this REST endpoint yet, so assistant-created
+        // resources go through unattributed until one is wired up.
+        let mut chunks = engine
+            .chat_response(system_message, messages, body.max_tokens, body.temperature, None)
+            .await;
+
+        if !body.stream {
+            let mut message = String::new();
+            let mut tool_calls = None;
+
+            while let Some(chunk) = chunks.next().await {
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(err) => return ChatCompletionApiResponse::Error(Json(ChatCompletionErrorResponse::from(err))),
+                };
+
+                message = chunk.message;
+                tool_calls = chunk.tool_calls;
+            }
+
+            return ChatCompletionApiResponse::Full(Json(ChatCompletionResponse {
+                id: uuid::Uuid::new_v4().to_string(),
+                object: "chat.completion".to_string(),
+                model: body.model,
+                choices: vec![ChatCompletionChoice {
+                    index: 0,
+                    message: ChatCompletionMessage {
+                        role: "assistant".to_string(),
+                        content: Some(message),
+                    },
+                    finish_reason: if tool_calls.is_some() { "tool_calls" } else { "stop" }.to_string(),
+                }],
+            }));
+        }
+
+        let completion_id = uuid::Uuid::new_v4().to_string();
+        let model = body.model;
+
+        let sse = stream! {
+            while let Some(chunk) = chunks.next().await {
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(err) => {
+                        let event = json!({
+                            "id": completion_id,
+                            "object": "chat.completion.chunk",
+                            "model": model,
+                            "error": { "message": err.to_string() },
+                        });
+
+                        yield event.to_string();
+                        return;
+                    }
+                };
+
+                let event = json!({
+                    "id": completion_id,
+                    "object": "chat.completion.chunk",
+                    "model": model,
+                    "choices": [{
+                        "index": 0,
+                        "delta": {
+                            "content": chunk.delta,
+                            "tool_calls": chunk.tool_calls,
+                        },
+                        "finish_reason": null,
+                    }],
+                });
+
+                yield event.to_string();
+            }
+
+            yield "[DONE]".to_string();
+        };
+
+        ChatCompletionApiResponse::Stream(EventStream::new(Box::pin(sse)))
+    }
+}
+
+impl From<SDKError> for ChatCompletionErrorResponse {
+    fn from(err: SDKError) -> Self {
+        ChatCompletionErrorResponse { error: err.to_string() }
+    }
+}
@@ -1,7 +1,12 @@
 use super::operations::TaskSuggestionInput;
+use super::v2::chat::{
+    ChatResponseChunk, ChatResponseChunkBuilder, ChatResponseFunctionCallBuilder, ChatResponseToolCall,
+    ChatResponseToolCallBuilder,
+};
 
 use crate::{
     backend::engine::SDKEngine,
+    errors::sdk::SDKError,
     resources::{
         messages::message::Message,
         tasks::{
@@ -12,36 +17,49 @@ use crate::{
 };
 
 use async_openai::types::{
-    ChatCompletionFunctionsArgs, ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
-    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
+    ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestToolMessageArgs,
+    ChatCompletionRequestUserMessageArgs, ChatCompletionToolArgs, ChatCompletionToolType,
+    CreateChatCompletionRequestArgs, FunctionObjectArgs,
 };
 
+use super::tools::all_tools;
+
 use async_stream::stream;
 use async_trait::async_trait;
-use schemars::{schema_for, JsonSchema};
+use schemars::JsonSchema;
+use serde::Deserialize;
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::pin::Pin;
 use tokio_stream::{Stream, StreamExt};
 use uuid::Uuid;
 
+// Hard stop on tool-call/response round trips so a model that keeps emitting
+// function calls (or a buggy tool) can't spin the worker task forever.
+const MAX_TOOL_CALL_STEPS: u8 = 8;
+
 #[async_trait]
 pub trait CognitionCapabilities {
-    async fn chat_completion(&self, system_message: String, user_message: String) -> String;
+    async fn chat_completion(&self, system_message: String, user_message: String) -> Result<String, SDKError>;
     async fn acquire_tasks_fingerprints(&self, number_of_tasks: u32, project_id: Option<Uuid>) -> Vec<String>;
     async fn chat_response(
         &self,
         system_message: String,
         messages: Vec<Message>,
-    ) -> Pin<Box<dyn Stream<Item = String> + Send>>;
+        max_tokens: Option<u16>,
+        temperature: Option<f32>,
+        owner_id: Option<Uuid>,
+    ) -> Pin<Box<dyn Stream<Item = Result<ChatResponseChunk, SDKError>> + Send>>;
+    async fn dispatch_function_call(&self, name: &str, arguments: &str, owner_id: Option<Uuid>) -> Value;
 
     fn calculate_task_fingerprint(task: Task) -> String;
     fn calculate_task_suggestion_fingerprint(task_suggestion: TaskSuggestionInput) -> String;
-    fn message_to_chat_completion(message: &Message) -> ChatCompletionRequestMessage;
+    fn message_to_chat_completion(message: &Message) -> Result<ChatCompletionRequestMessage, SDKError>;
 }
 
 #[async_trait]
 impl CognitionCapabilities for SDKEngine {
-    async fn chat_completion(&self, system_message: String, user_message: String) -> String {
+    async fn chat_completion(&self, system_message: String, user_message: String) -> Result<String, SDKError> {
         let request = CreateChatCompletionRequestArgs::default()
             .max_tokens(1024u16)
             .model(self.config.llm_model_name.clone())
@@ -49,20 +67,29 @@ impl CognitionCapabilities for SDKEngine {
                 ChatCompletionRequestSystemMessageArgs::default()
                     .content(system_message)
                     .build()
-                    .unwrap()
+                    .map_err(|err| SDKError::LLMRequestError(err.to_string()))?
                     .into(),
                 ChatCompletionRequestUserMessageArgs::default()
                     .content(user_message)
                     .build()
-                    .unwrap()
+                    .map_err(|err| SDKError::LLMRequestError(err.to_string()))?
                     .into(),
             ])
             .build()
-            .unwrap();
+            .map_err(|err| SDKError::LLMRequestError(err.to_string()))?;
 
-        let response = self.llm_client.chat().create(request).await.unwrap();
+        let response = self
+            .llm_client
+            .chat()
+            .create(request)
+            .await
+            .map_err(|err| SDKError::LLMRequestError(err.to_string()))?;
 
-        response.choices.first().unwrap().message.content.clone().unwrap()
+        response
+            .choices
+            .first()
+            .and_then(|choice| choice.message.content.clone())
+            .ok_or(SDKError::LLMStreamError)
     }
 
     fn calculate_task_fingerprint(task: Task) -> String {
@@ -126,75 +153,380 @@ impl CognitionCapabilities for SDKEngine {
         &self,
         system_message: String,
         messages: Vec<Message>,
-    ) -> Pin<Box<dyn Stream<Item = String> + Send>> {
-        let mut conversation_messages: Vec<ChatCompletionRequestMessage> =
-            messages.iter().map(Self::message_to_chat_completion).collect();
+        max_tokens: Option<u16>,
+        temperature: Option<f32>,
+        owner_id: Option<Uuid>,
+    ) -> Pin<Box<dyn Stream<Item = Result<ChatResponseChunk, SDKError>> + Send>> {
+        let max_tokens = max_tokens.unwrap_or(1024u16);
 
-        let mut messages: Vec<ChatCompletionRequestMessage> = vec![ChatCompletionRequestSystemMessageArgs::default()
+        let system_message = match ChatCompletionRequestSystemMessageArgs::default()
             .content(system_message)
             .build()
-            .unwrap()
-            .into()];
+            .map_err(|err| SDKError::LLMRequestError(err.to_string()))
+        {
+            Ok(system_message) => system_message,
+            Err(err) => return Box::pin(tokio_stream::once(Err(err))),
+        };
 
-        messages.append(&mut conversation_messages);
+        let mut conversation: Vec<ChatCompletionRequestMessage> = vec![system_message.into()];
 
-        let create_task_input_schema = schema_for!(CreateTaskLLMFunctionInput);
-
-        let create_task_function_def = json!({
-            "type": "object",
-            "properties": {
-                "input": &create_task_input_schema,
-            },
-            "required": ["input"],
-        });
+        for message in &messages {
+            match Self::message_to_chat_completion(message) {
+                Ok(message) => conversation.push(message),
+                Err(err) => return Box::pin(tokio_stream::once(Err(err))),
+            }
+        }
 
-        println!("create_task_function_def: {}", create_task_function_def);
+        let tools = all_tools();
 
-        let request = CreateChatCompletionRequestArgs::default()
-            .max_tokens(1024u16)
-            .model(self.config.llm_model_name.clone())
-            .messages(messages)
-            // .tools(value)
-            .functions([ChatCompletionFunctionsArgs::default()
-                .name("create_task")
-                .description("Create a task, complete the input object parameter inferred from the user's input.")
-                .parameters(create_task_function_def)
-                .build()
-                .unwrap()])
-            .function_call("auto")
-            .build()
-            .unwrap();
+        let tool_defs: Vec<_> = tools
+            .iter()
+            .map(|tool| {
+                ChatCompletionToolArgs::default()
+                    .r#type(ChatCompletionToolType::Function)
+                    .function(
+                        FunctionObjectArgs::default()
+                            .name(tool.name())
+                            .description(tool.description())
+                            .parameters(json!(tool.parameters()))
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap()
+            })
+            .collect();
 
-        let mut response = self.llm_client.chat().create_stream(request).await.unwrap();
+        let this = self.clone();
+        let message_id = Uuid::new_v4();
 
         Box::pin(stream! {
-            while let Some(response) = response.next().await {
-                println!("response: {:?}", response);
+            let mut message = String::new();
+
+            for _ in 0..MAX_TOOL_CALL_STEPS {
+                let mut request_builder = CreateChatCompletionRequestArgs::default();
+
+                request_builder
+                    .max_tokens(max_tokens)
+                    .model(this.config.llm_model_name.clone())
+                    .messages(conversation.clone())
+                    .tools(tool_defs.clone());
+
+                if let Some(temperature) = temperature {
+                    request_builder.temperature(temperature);
+                }
+
+                let request = match request_builder.build() {
+                    Ok(request) => request,
+                    Err(err) => {
+                        yield Err(SDKError::LLMRequestError(err.to_string()));
+                        return;
+                    }
+                };
+
+                let mut response = match this.llm_client.chat().create_stream(request).await {
+                    Ok(response) => response,
+                    Err(err) => {
+                        yield Err(SDKError::LLMRequestError(err.to_string()));
+                        return;
+                    }
+                };
+
+                // Keyed by the delta's `index` so multiple tool calls emitted in
+                // parallel can each accumulate their `id`/`function.arguments`
+                // fragments independently as they stream in across many SSE chunks.
+                let mut tool_call_ids: HashMap<u32, String> = HashMap::new();
+                let mut tool_call_names: HashMap<u32, String> = HashMap::new();
+                let mut tool_call_arguments: HashMap<u32, String> = HashMap::new();
+
+                while let Some(chunk) = response.next().await {
+                    let chunk = match chunk {
+                        Ok(chunk) => chunk,
+                        Err(err) => {
+                            yield Err(SDKError::LLMRequestError(err.to_string()));
+                            return;
+                        }
+                    };
+
+                    let choice = match chunk.choices.first() {
+                        Some(choice) => choice.clone(),
+                        None => {
+                            yield Err(SDKError::LLMStreamError);
+                            return;
+                        }
+                    };
+
+                    if let Some(tool_call_chunks) = choice.delta.tool_calls {
+                        for tool_call_chunk in tool_call_chunks {
+                            accumulate_tool_call_delta(
+                                &mut tool_call_ids,
+                                &mut tool_call_names,
+                                &mut tool_call_arguments,
+                                tool_call_chunk.index,
+                                tool_call_chunk.id,
+                                tool_call_chunk.function.as_ref().and_then(|f| f.name.clone()),
+                                tool_call_chunk.function.and_then(|f| f.arguments),
+                            );
+                        }
+
+                        continue;
+                    }
+
+                    if let Some(content) = choice.delta.content {
+                        message.push_str(&content);
+
+                        yield Ok(ChatResponseChunkBuilder::default()
+                            .delta(content)
+                            .message(message.clone())
+                            .message_id(Some(message_id))
+                            .tool_calls(None)
+                            .build()
+                            .unwrap());
+                    }
+                }
 
-                match response.unwrap().choices.first().unwrap().delta.content.clone() {
-                    Some(content) => yield content,
-                    None => break
+                if tool_call_ids.is_empty() {
+                    return;
                 }
+
+                let accumulated = finalize_tool_calls(tool_call_ids, tool_call_names, tool_call_arguments);
+                let mut tool_calls: Vec<ChatResponseToolCall> = Vec::with_capacity(accumulated.len());
+
+                for (id, name, arguments) in accumulated {
+                    let result = this.dispatch_function_call(&name, &arguments, owner_id).await;
+
+                    conversation.push(
+                        ChatCompletionRequestToolMessageArgs::default()
+                            .tool_call_id(id.clone())
+                            .content(result.to_string())
+                            .build()
+                            .unwrap()
+                            .into(),
+                    );
+
+                    tool_calls.push(
+                        ChatResponseToolCallBuilder::default()
+                            .id(Some(id))
+                            .r#type(Some("function".to_string()))
+                            .function(Some(
+                                ChatResponseFunctionCallBuilder::default()
+                                    .name(Some(name))
+                                    .arguments(Some(arguments))
+                                    .build()
+                                    .unwrap(),
+                            ))
+                            .build()
+                            .unwrap(),
+                    );
+                }
+
+                yield Ok(ChatResponseChunkBuilder::default()
+                    .delta(String::new())
+                    .message(message.clone())
+                    .message_id(Some(message_id))
+                    .tool_calls(Some(tool_calls))
+                    .build()
+                    .unwrap());
             }
         })
     }
 
-    fn message_to_chat_completion(message: &'_ Message) -> ChatCompletionRequestMessage {
-        let val: Value = serde_json::from_str(message.content.as_str()).unwrap();
-
-        match val.clone() {
-            Value::Object(obj) => match obj.get("role").unwrap().as_str().unwrap() {
-                "user" => ChatCompletionRequestMessage::User(serde_json::from_value(val).unwrap()),
-                "assistant" => ChatCompletionRequestMessage::Assistant(serde_json::from_value(val).unwrap()),
-                "tool" | "function" => todo!(),
-                _ => todo!(),
-            },
-            _ => todo!(),
+    async fn dispatch_function_call(&self, name: &str, arguments: &str, owner_id: Option<Uuid>) -> Value {
+        let arguments: Value = match serde_json::from_str(arguments) {
+            Ok(arguments) => arguments,
+            Err(err) => return json!({ "error": format!("invalid JSON arguments for tool `{name}`: {err}") }),
+        };
+
+        let tools = all_tools();
+
+        let Some(tool) = tools.iter().find(|tool| tool.name() == name) else {
+            return json!({ "error": format!("unknown tool: {name}") });
+        };
+
+        match tool.invoke(self, owner_id, arguments).await {
+            Ok(result) => result,
+            Err(err) => json!({ "error": err.to_string() }),
         }
     }
+
+    fn message_to_chat_completion(message: &'_ Message) -> Result<ChatCompletionRequestMessage, SDKError> {
+        let val: Value = serde_json::from_str(message.content.as_str())
+            .map_err(|err| SDKError::SerializationError(err.to_string()))?;
+
+        let Value::Object(ref obj) = val else {
+            return Err(SDKError::SerializationError(
+                "chat message content must be a JSON object".to_string(),
+            ));
+        };
+
+        let role = obj
+            .get("role")
+            .and_then(|role| role.as_str())
+            .ok_or_else(|| SDKError::SerializationError("chat message is missing a `role` field".to_string()))?;
+
+        let to_chat_completion_message = |val: Value| {
+            serde_json::from_value(val).map_err(|err| SDKError::SerializationError(err.to_string()))
+        };
+
+        match role {
+            "user" => Ok(ChatCompletionRequestMessage::User(to_chat_completion_message(val)?)),
+            "assistant" => Ok(ChatCompletionRequestMessage::Assistant(to_chat_completion_message(val)?)),
+            "function" => Ok(ChatCompletionRequestMessage::Function(to_chat_completion_message(val)?)),
+            "tool" => Ok(ChatCompletionRequestMessage::Tool(to_chat_completion_message(val)?)),
+            _ => Err(SDKError::SerializationError(format!("unsupported chat message role: {role}"))),
+        }
+    }
+}
+
+/// Folds one SSE delta's `id`/`function.name`/`function.arguments` fragments
+/// into the per-index accumulators, keyed by the delta's `index` so multiple
+/// tool calls streamed in parallel don't interleave into each other.
+fn accumulate_tool_call_delta(
+    tool_call_ids: &mut HashMap<u32, String>,
+    tool_call_names: &mut HashMap<u32, String>,
+    tool_call_arguments: &mut HashMap<u32, String>,
+    index: u32,
+    id: Option<String>,
+    name: Option<String>,
+    arguments: Option<String>,
+) {
+    if let Some(id) = id {
+        tool_call_ids.entry(index).or_default().push_str(&id);
+    }
+
+    if let Some(name) = name {
+        tool_call_names.entry(index).or_default().push_str(&name);
+    }
+
+    if let Some(arguments) = arguments {
+        tool_call_arguments.entry(index).or_default().push_str(&arguments);
+    }
+}
+
+/// Drains the per-index accumulators into `(id, name, arguments)` triples,
+/// ordered by index so tool calls are dispatched in the order the model
+/// emitted them.
+fn finalize_tool_calls(
+    mut tool_call_ids: HashMap<u32, String>,
+    mut tool_call_names: HashMap<u32, String>,
+    mut tool_call_arguments: HashMap<u32, String>,
+) -> Vec<(String, String, String)> {
+    let mut indices: Vec<u32> = tool_call_ids.keys().copied().collect();
+    indices.sort_unstable();
+
+    indices
+        .into_iter()
+        .map(|index| {
+            (
+                tool_call_ids.remove(&index).unwrap_or_default(),
+                tool_call_names.remove(&index).unwrap_or_default(),
+                tool_call_arguments.remove(&index).unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::messages::message::MessageBuilder;
+
+    #[test]
+    fn accumulate_tool_call_delta_joins_fragments_by_index() {
+        let mut ids = HashMap::new();
+        let mut names = HashMap::new();
+        let mut arguments = HashMap::new();
+
+        // First tool call (index 0) streams in over three deltas.
+        accumulate_tool_call_delta(
+            &mut ids,
+            &mut names,
+            &mut arguments,
+            0,
+            Some("call_1".into()),
+            Some("create_task".into()),
+            Some("{\"tit".into()),
+        );
+        accumulate_tool_call_delta(&mut ids, &mut names, &mut arguments, 0, None, None, Some("le\":".into()));
+        accumulate_tool_call_delta(&mut ids, &mut names, &mut arguments, 0, None, None, Some("\"Do it\"}".into()));
+
+        // A second, parallel tool call (index 1) interleaves with the first.
+        accumulate_tool_call_delta(
+            &mut ids,
+            &mut names,
+            &mut arguments,
+            1,
+            Some("call_2".into()),
+            Some("create_project".into()),
+            Some("{}".into()),
+        );
+
+        let accumulated = finalize_tool_calls(ids, names, arguments);
+
+        assert_eq!(
+            accumulated,
+            vec![
+                ("call_1".to_string(), "create_task".to_string(), "{\"title\":\"Do it\"}".to_string()),
+                ("call_2".to_string(), "create_project".to_string(), "{}".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn finalize_tool_calls_orders_by_index_not_insertion_order() {
+        let mut ids = HashMap::new();
+        let mut names = HashMap::new();
+        let mut arguments = HashMap::new();
+
+        // Accumulate index 1 before index 0 to prove ordering isn't insertion order.
+        accumulate_tool_call_delta(
+            &mut ids,
+            &mut names,
+            &mut arguments,
+            1,
+            Some("call_second".into()),
+            Some("b".into()),
+            Some("{}".into()),
+        );
+        accumulate_tool_call_delta(
+            &mut ids,
+            &mut names,
+            &mut arguments,
+            0,
+            Some("call_first".into()),
+            Some("a".into()),
+            Some("{}".into()),
+        );
+
+        let accumulated = finalize_tool_calls(ids, names, arguments);
+
+        assert_eq!(accumulated[0].0, "call_first");
+        assert_eq!(accumulated[1].0, "call_second");
+    }
+
+    #[test]
+    fn message_to_chat_completion_rejects_unknown_roles_instead_of_panicking() {
+        let message = MessageBuilder::default()
+            .content(json!({ "role": "system-prompt-injection", "content": "hi" }).to_string())
+            .build()
+            .unwrap();
+
+        let err = SDKEngine::message_to_chat_completion(&message).unwrap_err();
+
+        assert!(matches!(err, SDKError::SerializationError(_)));
+    }
+
+    #[test]
+    fn message_to_chat_completion_rejects_non_object_content_instead_of_panicking() {
+        let message = MessageBuilder::default().content(json!("just a string").to_string()).build().unwrap();
+
+        let err = SDKEngine::message_to_chat_completion(&message).unwrap_err();
+
+        assert!(matches!(err, SDKError::SerializationError(_)));
+    }
 }
 
-#[derive(Clone, Default, JsonSchema)]
+#[derive(Clone, Default, Deserialize, JsonSchema)]
 pub struct CreateTaskLLMFunctionInput {
     pub title: String,
 
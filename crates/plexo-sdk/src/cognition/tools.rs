@@ -0,0 +1,196 @@
+use super::suggestions::CreateTaskLLMFunctionInput;
+
+use crate::{
+    backend::engine::SDKEngine,
+    errors::sdk::SDKError,
+    resources::{
+        projects::operations::{CreateProjectInputBuilder, ProjectCrudOperations},
+        tasks::{
+            operations::{CreateTaskInputBuilder, GetTasksInputBuilder, TaskCrudOperations, UpdateTaskInputBuilder},
+            task::{TaskPriority, TaskStatus},
+        },
+    },
+};
+
+use async_trait::async_trait;
+use schemars::{schema::RootSchema, schema_for, JsonSchema};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// A function the chat assistant can call. Implementations are enumerated by
+/// `chat_response` when it builds the completion request and routed back to
+/// by name once the model selects one.
+#[async_trait]
+pub trait CognitionTool: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    fn parameters(&self) -> RootSchema;
+    async fn invoke(&self, engine: &SDKEngine, owner_id: Option<Uuid>, input: Value) -> Result<Value, SDKError>;
+}
+
+/// The tools the chat assistant has access to, in the order they're
+/// advertised to the model.
+pub fn all_tools() -> Vec<Box<dyn CognitionTool>> {
+    vec![
+        Box::new(CreateTaskTool),
+        Box::new(UpdateTaskTool),
+        Box::new(QueryTasksTool),
+        Box::new(CreateProjectTool),
+    ]
+}
+
+pub struct CreateTaskTool;
+
+#[async_trait]
+impl CognitionTool for CreateTaskTool {
+    fn name(&self) -> &'static str {
+        "create_task"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a task, complete the input object parameter inferred from the user's input."
+    }
+
+    fn parameters(&self) -> RootSchema {
+        schema_for!(CreateTaskLLMFunctionInput)
+    }
+
+    async fn invoke(&self, engine: &SDKEngine, owner_id: Option<Uuid>, input: Value) -> Result<Value, SDKError> {
+        let input: CreateTaskLLMFunctionInput = serde_json::from_value(input)?;
+
+        let create_task_input = CreateTaskInputBuilder::default()
+            .title(input.title)
+            .description(input.description)
+            .status(input.status)
+            .priority(input.priority)
+            .due_date(input.due_date.and_then(|d| d.parse().ok()))
+            .project_id(input.project_id.and_then(|id| id.parse().ok()))
+            .lead_id(input.lead_id.and_then(|id| id.parse().ok()))
+            .owner_id(owner_id)
+            .parent_id(input.parent_id.and_then(|id| id.parse().ok()))
+            .build()
+            .map_err(|err| SDKError::BuilderError(err.to_string()))?;
+
+        let task = engine.create_task(create_task_input).await?;
+
+        Ok(json!(task))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateTaskInput {
+    pub task_id: Uuid,
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
+}
+
+pub struct UpdateTaskTool;
+
+#[async_trait]
+impl CognitionTool for UpdateTaskTool {
+    fn name(&self) -> &'static str {
+        "update_task"
+    }
+
+    fn description(&self) -> &'static str {
+        "Update an existing task's status and/or priority."
+    }
+
+    fn parameters(&self) -> RootSchema {
+        schema_for!(UpdateTaskInput)
+    }
+
+    async fn invoke(&self, engine: &SDKEngine, _owner_id: Option<Uuid>, input: Value) -> Result<Value, SDKError> {
+        let input: UpdateTaskInput = serde_json::from_value(input)?;
+
+        let update_task_input = UpdateTaskInputBuilder::default()
+            .status(input.status)
+            .priority(input.priority)
+            .build()
+            .map_err(|err| SDKError::BuilderError(err.to_string()))?;
+
+        let task = engine.update_task(input.task_id, update_task_input).await?;
+
+        Ok(json!(task))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct QueryTasksInput {
+    pub limit: Option<i32>,
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
+    pub project_id: Option<Uuid>,
+}
+
+pub struct QueryTasksTool;
+
+#[async_trait]
+impl CognitionTool for QueryTasksTool {
+    fn name(&self) -> &'static str {
+        "query_tasks"
+    }
+
+    fn description(&self) -> &'static str {
+        "Query existing tasks by status, priority and/or project."
+    }
+
+    fn parameters(&self) -> RootSchema {
+        schema_for!(QueryTasksInput)
+    }
+
+    async fn invoke(&self, engine: &SDKEngine, _owner_id: Option<Uuid>, input: Value) -> Result<Value, SDKError> {
+        let input: QueryTasksInput = serde_json::from_value(input)?;
+
+        let filter = GetTasksInputBuilder::default()
+            .limit(input.limit.unwrap_or(20))
+            .status(input.status)
+            .priority(input.priority)
+            .project_id(input.project_id)
+            .build()
+            .map_err(|err| SDKError::BuilderError(err.to_string()))?;
+
+        let tasks = engine.get_tasks(Some(filter)).await?;
+
+        Ok(json!(tasks))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateProjectToolInput {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+pub struct CreateProjectTool;
+
+#[async_trait]
+impl CognitionTool for CreateProjectTool {
+    fn name(&self) -> &'static str {
+        "create_project"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a new project."
+    }
+
+    fn parameters(&self) -> RootSchema {
+        schema_for!(CreateProjectToolInput)
+    }
+
+    async fn invoke(&self, engine: &SDKEngine, owner_id: Option<Uuid>, input: Value) -> Result<Value, SDKError> {
+        let input: CreateProjectToolInput = serde_json::from_value(input)?;
+
+        let create_project_input = CreateProjectInputBuilder::default()
+            .name(input.name)
+            .description(input.description)
+            .owner_id(owner_id)
+            .build()
+            .map_err(|err| SDKError::BuilderError(err.to_string()))?;
+
+        let project = engine.create_project(create_project_input).await?;
+
+        Ok(json!(project))
+    }
+}